@@ -0,0 +1,144 @@
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+const TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+const SCOPE: &str = "https://www.googleapis.com/auth/cloud-translation";
+/// Refresh a bit before the real expiry so in-flight requests never race a dead token.
+const REFRESH_SKEW_SECONDS: i64 = 60;
+
+/// Failure while loading credentials or minting an access token.
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("missing {0}")]
+    MissingEnvVar(&'static str),
+    #[error("failed to read service account key at {path}: {source}")]
+    ReadKeyFile {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("service account key is not valid JSON: {0}")]
+    InvalidKeyJson(#[from] serde_json::Error),
+    #[error("service account private key is not valid PEM: {0}")]
+    InvalidPrivateKey(jsonwebtoken::errors::Error),
+    #[error("failed to sign JWT assertion: {0}")]
+    SignJwt(jsonwebtoken::errors::Error),
+    #[error("token request failed: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+#[derive(Debug, Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    #[serde(default)]
+    token_uri: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Claims {
+    iss: String,
+    scope: String,
+    aud: String,
+    iat: i64,
+    exp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: i64,
+}
+
+#[derive(Debug)]
+struct TokenState {
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// Holds a GCP service-account key and hands out short-lived OAuth access
+/// tokens, minting a new one whenever the cached token is about to expire.
+#[derive(Debug)]
+pub struct Auth {
+    pub project: String,
+    service_account: ServiceAccountKey,
+    state: Mutex<TokenState>,
+}
+
+impl Auth {
+    /// Loads credentials from `GCP_AUTH` (path to a service-account JSON
+    /// key), falling back to `GOOGLE_APPLICATION_CREDENTIALS` as an alternate
+    /// env var pointing at the same kind of key file — this does not
+    /// implement the full Application Default Credentials resolution chain
+    /// (no gcloud user credentials, no GCE/GKE metadata server). Also reads
+    /// the target project from `GCP_PROJECT`, then mints an initial access
+    /// token.
+    pub async fn load_from_env(client: &reqwest::Client) -> Result<Self, AuthError> {
+        let key_path = std::env::var("GCP_AUTH")
+            .or_else(|_| std::env::var("GOOGLE_APPLICATION_CREDENTIALS"))
+            .map_err(|_| AuthError::MissingEnvVar("GCP_AUTH or GOOGLE_APPLICATION_CREDENTIALS"))?;
+        let project = std::env::var("GCP_PROJECT")
+            .map_err(|_| AuthError::MissingEnvVar("GCP_PROJECT"))?;
+
+        let key_json =
+            std::fs::read_to_string(&key_path).map_err(|source| AuthError::ReadKeyFile {
+                path: key_path.clone(),
+                source,
+            })?;
+        let service_account: ServiceAccountKey = serde_json::from_str(&key_json)?;
+
+        let (token, expires_at) = Self::mint_token(client, &service_account).await?;
+        Ok(Self {
+            project,
+            service_account,
+            state: Mutex::new(TokenState { token, expires_at }),
+        })
+    }
+
+    /// Returns a valid access token, refreshing it first if it is within the
+    /// skew window of expiring.
+    pub async fn token(&self, client: &reqwest::Client) -> Result<String, AuthError> {
+        let mut state = self.state.lock().await;
+        if state.expires_at - Utc::now() < Duration::seconds(REFRESH_SKEW_SECONDS) {
+            let (token, expires_at) = Self::mint_token(client, &self.service_account).await?;
+            state.token = token;
+            state.expires_at = expires_at;
+        }
+        Ok(state.token.clone())
+    }
+
+    async fn mint_token(
+        client: &reqwest::Client,
+        service_account: &ServiceAccountKey,
+    ) -> Result<(String, DateTime<Utc>), AuthError> {
+        let now = Utc::now();
+        let aud = service_account
+            .token_uri
+            .clone()
+            .unwrap_or_else(|| TOKEN_URI.to_string());
+        let claims = Claims {
+            iss: service_account.client_email.clone(),
+            scope: SCOPE.to_string(),
+            aud: aud.clone(),
+            iat: now.timestamp(),
+            exp: (now + Duration::hours(1)).timestamp(),
+        };
+        let key = EncodingKey::from_rsa_pem(service_account.private_key.as_bytes())
+            .map_err(AuthError::InvalidPrivateKey)?;
+        let jwt = jsonwebtoken::encode(&Header::new(Algorithm::RS256), &claims, &key)
+            .map_err(AuthError::SignJwt)?;
+
+        let resp = client
+            .post(aud)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", jwt.as_str()),
+            ])
+            .send()
+            .await?;
+        let token_resp: TokenResponse = resp.json().await?;
+        let expires_at = now + Duration::seconds(token_resp.expires_in);
+        Ok((token_resp.access_token, expires_at))
+    }
+}
@@ -0,0 +1,231 @@
+use teloxide::{
+    dispatching::dialogue::InMemStorage,
+    prelude::*,
+    types::{CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, MessageId},
+};
+
+use crate::{i18n, LANGUAGES, TRANSLATOR};
+
+pub type MyDialogue = Dialogue<State, InMemStorage<State>>;
+pub type MyStorage = InMemStorage<State>;
+
+const LANGUAGES_PER_PAGE: usize = 8;
+
+/// Guided `/translate` flow: pick a target language from an inline keyboard,
+/// then a source language (or auto-detect), then send the text to translate.
+#[derive(Clone, Default)]
+pub enum State {
+    #[default]
+    Idle,
+    ChoosingTargetLanguage {
+        page: usize,
+    },
+    ChoosingSourceLanguage {
+        prompt_message_id: MessageId,
+        to_lang: String,
+        page: usize,
+    },
+    AwaitingText {
+        prompt_message_id: MessageId,
+        from_lang: Option<String>,
+        to_lang: String,
+    },
+}
+
+fn sorted_languages() -> Vec<(String, String)> {
+    let mut languages: Vec<(String, String)> = LANGUAGES
+        .get()
+        .unwrap()
+        .iter()
+        .map(|(name, code)| (name.clone(), code.clone()))
+        .collect();
+    languages.sort_by(|a, b| a.0.cmp(&b.0));
+    languages
+}
+
+fn language_keyboard(page: usize, include_auto_detect: bool) -> InlineKeyboardMarkup {
+    let languages = sorted_languages();
+    let start = page * LANGUAGES_PER_PAGE;
+
+    let mut rows: Vec<Vec<InlineKeyboardButton>> = languages
+        .iter()
+        .skip(start)
+        .take(LANGUAGES_PER_PAGE)
+        .map(|(name, code)| {
+            vec![InlineKeyboardButton::callback(
+                name.clone(),
+                format!("lang:{code}"),
+            )]
+        })
+        .collect();
+
+    if include_auto_detect && page == 0 {
+        rows.insert(
+            0,
+            vec![InlineKeyboardButton::callback("Auto-detect", "auto")],
+        );
+    }
+
+    let mut nav_row = Vec::new();
+    if page > 0 {
+        nav_row.push(InlineKeyboardButton::callback(
+            "« Prev",
+            format!("page:{}", page - 1),
+        ));
+    }
+    if start + LANGUAGES_PER_PAGE < languages.len() {
+        nav_row.push(InlineKeyboardButton::callback(
+            "Next »",
+            format!("page:{}", page + 1),
+        ));
+    }
+    if !nav_row.is_empty() {
+        rows.push(nav_row);
+    }
+
+    InlineKeyboardMarkup::new(rows)
+}
+
+/// Entry point for `/translate`: prompts for a target language.
+pub async fn start(bot: Bot, dialogue: MyDialogue, msg: Message) -> ResponseResult<()> {
+    bot.send_message(msg.chat.id, "Choose a target language:")
+        .reply_markup(language_keyboard(0, false))
+        .await?;
+    dialogue
+        .update(State::ChoosingTargetLanguage { page: 0 })
+        .await
+        .expect("in-memory dialogue storage is infallible");
+    Ok(())
+}
+
+/// Handles taps on the language-picker / pagination keyboards.
+pub async fn handle_callback(bot: Bot, dialogue: MyDialogue, q: CallbackQuery) -> ResponseResult<()> {
+    let (Some(data), Some(message)) = (q.data.clone(), q.message.clone()) else {
+        return Ok(());
+    };
+    let state = dialogue
+        .get()
+        .await
+        .expect("in-memory dialogue storage is infallible")
+        .unwrap_or_default();
+
+    match state {
+        State::ChoosingTargetLanguage { page: _ } => {
+            if let Some(new_page) = data.strip_prefix("page:").and_then(|n| n.parse().ok()) {
+                bot.edit_message_reply_markup(message.chat.id, message.id)
+                    .reply_markup(language_keyboard(new_page, false))
+                    .await?;
+                dialogue
+                    .update(State::ChoosingTargetLanguage { page: new_page })
+                    .await
+                    .expect("in-memory dialogue storage is infallible");
+            } else if let Some(to_lang) = data.strip_prefix("lang:") {
+                let to_lang = to_lang.to_string();
+                bot.edit_message_text(
+                    message.chat.id,
+                    message.id,
+                    "Choose a source language, or auto-detect:",
+                )
+                .reply_markup(language_keyboard(0, true))
+                .await?;
+                dialogue
+                    .update(State::ChoosingSourceLanguage {
+                        prompt_message_id: message.id,
+                        to_lang,
+                        page: 0,
+                    })
+                    .await
+                    .expect("in-memory dialogue storage is infallible");
+            }
+        }
+        State::ChoosingSourceLanguage {
+            prompt_message_id,
+            to_lang,
+            page: _,
+        } => {
+            if let Some(new_page) = data.strip_prefix("page:").and_then(|n| n.parse().ok()) {
+                bot.edit_message_reply_markup(message.chat.id, message.id)
+                    .reply_markup(language_keyboard(new_page, true))
+                    .await?;
+                dialogue
+                    .update(State::ChoosingSourceLanguage {
+                        prompt_message_id,
+                        to_lang,
+                        page: new_page,
+                    })
+                    .await
+                    .expect("in-memory dialogue storage is infallible");
+            } else {
+                let from_lang = if data == "auto" {
+                    None
+                } else {
+                    data.strip_prefix("lang:").map(str::to_string)
+                };
+                bot.edit_message_text(message.chat.id, message.id, "Send the text to translate.")
+                    .await?;
+                dialogue
+                    .update(State::AwaitingText {
+                        prompt_message_id,
+                        from_lang,
+                        to_lang,
+                    })
+                    .await
+                    .expect("in-memory dialogue storage is infallible");
+            }
+        }
+        State::Idle | State::AwaitingText { .. } => {}
+    }
+
+    bot.answer_callback_query(q.id).await?;
+    Ok(())
+}
+
+/// Handles the plain-text message sent once a target (and optionally
+/// source) language has been picked, translating it and editing the
+/// original prompt message in place with the result.
+pub async fn receive_text(
+    bot: Bot,
+    dialogue: MyDialogue,
+    msg: Message,
+    (prompt_message_id, from_lang, to_lang): (MessageId, Option<String>, String),
+) -> ResponseResult<()> {
+    let translator = TRANSLATOR.get().unwrap().as_ref();
+    let locale = msg.from().and_then(|user| user.language_code.clone());
+
+    let Some(text) = msg.text() else {
+        return Ok(());
+    };
+
+    let from_lang = match from_lang {
+        Some(from_lang) => from_lang,
+        None => match translator.detect(text).await {
+            Ok(from_lang) => from_lang,
+            Err(err) => {
+                bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                    .await?;
+                dialogue
+                    .exit()
+                    .await
+                    .expect("in-memory dialogue storage is infallible");
+                return Ok(());
+            }
+        },
+    };
+
+    match translator.translate(&from_lang, &to_lang, text).await {
+        Ok(translated) => {
+            bot.edit_message_text(msg.chat.id, prompt_message_id, translated)
+                .await?;
+        }
+        Err(err) => {
+            bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                .await?;
+        }
+    }
+
+    dialogue
+        .exit()
+        .await
+        .expect("in-memory dialogue storage is infallible");
+    Ok(())
+}
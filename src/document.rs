@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use teloxide::net::Download;
+use teloxide::prelude::*;
+use teloxide::types::InputFile;
+
+use crate::{i18n, LANGUAGES, TRANSLATOR};
+
+const TRANSLATABLE_EXTENSIONS: &[&str] = &["txt", "md", "srt"];
+/// Google's v2 endpoint caps a single request at 128 `q` segments and around
+/// 30 KiB of payload; stay comfortably under both so large files are sent in
+/// grouped calls rather than one request per line.
+const MAX_BATCH_SEGMENTS: usize = 100;
+const MAX_BATCH_BYTES: usize = 25_000;
+
+/// A single line of the source file, split apart from its line ending so
+/// reassembly can reproduce the original bytes (including `\r\n` and a
+/// missing/trailing terminator) exactly.
+struct Line {
+    content: String,
+    ending: &'static str,
+}
+
+fn split_lines(text: &str) -> Vec<Line> {
+    text.split_inclusive('\n')
+        .map(|line| {
+            if let Some(content) = line.strip_suffix("\r\n") {
+                Line {
+                    content: content.to_string(),
+                    ending: "\r\n",
+                }
+            } else if let Some(content) = line.strip_suffix('\n') {
+                Line {
+                    content: content.to_string(),
+                    ending: "\n",
+                }
+            } else {
+                Line {
+                    content: line.to_string(),
+                    ending: "",
+                }
+            }
+        })
+        .collect()
+}
+
+/// Whether an SRT line is structural rather than translatable text: a bare
+/// cue sequence number, or a `00:00:01,000 --> 00:00:04,000` timecode.
+fn is_srt_structural_line(content: &str) -> bool {
+    let trimmed = content.trim();
+    (!trimmed.is_empty() && trimmed.chars().all(|c| c.is_ascii_digit())) || trimmed.contains("-->")
+}
+
+/// Groups the indices of translatable lines into batches that respect both
+/// Google's segment-count and payload-size limits. Blank lines (and, for
+/// SRT files, cue sequence numbers and timecodes) are left out so they pass
+/// through untranslated.
+fn batch_translatable_indices(lines: &[Line], is_srt: bool) -> Vec<Vec<usize>> {
+    let mut batches = Vec::new();
+    let mut current = Vec::new();
+    let mut current_bytes = 0usize;
+
+    for (index, line) in lines.iter().enumerate() {
+        if line.content.trim().is_empty() || (is_srt && is_srt_structural_line(&line.content)) {
+            continue;
+        }
+
+        let size = line.content.len();
+        if !current.is_empty()
+            && (current.len() >= MAX_BATCH_SEGMENTS || current_bytes + size > MAX_BATCH_BYTES)
+        {
+            batches.push(std::mem::take(&mut current));
+            current_bytes = 0;
+        }
+        current.push(index);
+        current_bytes += size;
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+    batches
+}
+
+/// Whether `msg` carries an attached document this bot knows how to
+/// translate, based on its file extension.
+pub fn is_translatable_document(msg: &Message) -> bool {
+    msg.document()
+        .and_then(|document| document.file_name.as_deref())
+        .and_then(|name| Path::new(name).extension())
+        .and_then(|ext| ext.to_str())
+        .map(|ext| TRANSLATABLE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// Downloads an attached `.txt`/`.md`/`.srt` file, translates it line by
+/// line (blank lines are preserved but not sent for translation — for
+/// `.srt` files, cue sequence numbers and timecodes are preserved too — and
+/// original line endings are kept byte-for-byte), and replies with the
+/// translated file. The target language is read from the message caption,
+/// defaulting to English when none is given.
+pub async fn answer(bot: Bot, msg: Message) -> ResponseResult<()> {
+    let translator = TRANSLATOR.get().unwrap().as_ref();
+    let locale = msg.from().and_then(|user| user.language_code.clone());
+    let document = msg.document().expect("is_translatable_document checked this");
+    let is_srt = document
+        .file_name
+        .as_deref()
+        .and_then(|name| Path::new(name).extension())
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("srt"))
+        .unwrap_or(false);
+
+    let to_lang_input = msg.caption().unwrap_or("en").to_string();
+    let to_lang = LANGUAGES
+        .get()
+        .unwrap()
+        .get(&to_lang_input)
+        .cloned()
+        .unwrap_or(to_lang_input);
+
+    let file = bot.get_file(&document.file.id).await?;
+    let mut bytes = Vec::new();
+    bot.download_file(&file.path, &mut bytes).await?;
+    let Ok(text) = String::from_utf8(bytes) else {
+        bot.send_message(msg.chat.id, i18n::t(locale.as_deref(), "error_internal"))
+            .await?;
+        return Ok(());
+    };
+
+    let result = translator.detect(&text).await;
+    let from_lang = match result {
+        Ok(from_lang) => from_lang,
+        Err(err) => {
+            bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let mut lines = split_lines(&text);
+    for batch in batch_translatable_indices(&lines, is_srt) {
+        let texts: Vec<String> = batch.iter().map(|&i| lines[i].content.clone()).collect();
+        let result = translator.translate_batch(&from_lang, &to_lang, &texts).await;
+        let translated = match result {
+            Ok(translated) => translated,
+            Err(err) => {
+                bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                    .await?;
+                return Ok(());
+            }
+        };
+        if translated.len() != batch.len() {
+            bot.send_message(msg.chat.id, i18n::t(locale.as_deref(), "error_internal"))
+                .await?;
+            return Ok(());
+        }
+        for (&index, translated_content) in batch.iter().zip(translated) {
+            lines[index].content = translated_content;
+        }
+    }
+
+    let mut translated_text = String::new();
+    for line in &lines {
+        translated_text.push_str(&line.content);
+        translated_text.push_str(line.ending);
+    }
+
+    let file_name = document
+        .file_name
+        .clone()
+        .unwrap_or_else(|| "translated.txt".to_string());
+    bot.send_document(
+        msg.chat.id,
+        InputFile::memory(translated_text.into_bytes()).file_name(file_name),
+    )
+    .await?;
+
+    Ok(())
+}
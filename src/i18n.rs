@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use tokio::sync::OnceCell;
+
+use crate::translator::TranslateError;
+
+const DEFAULT_LOCALE: &str = "en";
+
+/// Message catalogs embedded at compile time, keyed by Telegram locale code.
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.json")),
+    ("uk", include_str!("../locales/uk.json")),
+];
+
+static CATALOGS: OnceCell<HashMap<&'static str, HashMap<String, String>>> = OnceCell::const_new();
+
+/// Parses every embedded locale catalog. Must be called once at startup
+/// before [`t`] is used.
+pub fn load() {
+    let catalogs = LOCALES
+        .iter()
+        .map(|(locale, json)| {
+            let messages: HashMap<String, String> = serde_json::from_str(json)
+                .unwrap_or_else(|e| panic!("invalid locale catalog {locale}: {e}"));
+            (*locale, messages)
+        })
+        .collect();
+    CATALOGS.set(catalogs).unwrap();
+}
+
+/// Resolves `key` in the catalog for `locale`, falling back to `en` when the
+/// locale or the key itself isn't found.
+pub fn t(locale: Option<&str>, key: &str) -> String {
+    let catalogs = CATALOGS.get().expect("i18n::load was not called");
+
+    locale
+        .and_then(|locale| catalogs.get(locale))
+        .and_then(|catalog| catalog.get(key))
+        .or_else(|| catalogs.get(DEFAULT_LOCALE).and_then(|catalog| catalog.get(key)))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Resolves a localized, user-facing message for a translator failure.
+pub fn translate_error(locale: Option<&str>, err: &TranslateError) -> String {
+    let key = match err {
+        TranslateError::Auth(_) | TranslateError::AuthenticationFailed => "error_auth",
+        TranslateError::QuotaExceeded => "error_quota",
+        TranslateError::UnsupportedLanguage(_) => "error_unsupported_language",
+        TranslateError::Request(_) | TranslateError::Provider { .. } => "error_internal",
+    };
+    t(locale, key)
+}
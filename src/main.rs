@@ -1,124 +1,24 @@
+mod auth;
+mod dialogue;
+mod document;
+mod i18n;
+mod translator;
+
 use std::collections::HashMap;
-use teloxide::{prelude::*, utils::command::BotCommands};
-use tokio::sync::OnceCell;
 
-#[derive(Debug)]
-pub struct Auth {
-    pub token: String,
-    pub project: String,
-}
+use dialogue::{MyStorage, State};
+use teloxide::{
+    dispatching::{dialogue::InMemStorage, UpdateFilterExt},
+    prelude::*,
+    types::CallbackQuery,
+    utils::command::BotCommands,
+};
+use tokio::sync::OnceCell;
+use translator::Translator;
 
-static CLIENT: OnceCell<reqwest::Client> = OnceCell::const_new();
-static AUTH: OnceCell<Auth> = OnceCell::const_new();
+static TRANSLATOR: OnceCell<Box<dyn Translator + Send + Sync>> = OnceCell::const_new();
 static LANGUAGES: OnceCell<HashMap<String, String>> = OnceCell::const_new();
 
-async fn get_languages(
-    client: &reqwest::Client,
-    auth: &Auth,
-) -> reqwest::Result<HashMap<String, String>> {
-    const URL: &str = "https://translation.googleapis.com/language/translate/v2/languages";
-
-    let resp = client
-        .post(URL)
-        .header("Authorization", format!("Bearer {}", auth.token))
-        .header("x-goog-user-project", &auth.project)
-        .header("Content-Type", "application/json; charset=utf-8")
-        .body(r#"{"target": "en"}"#)
-        .send()
-        .await?;
-    let resp_json: serde_json::Value = resp.json().await?;
-    log::trace!(
-        "get_languages response: {}",
-        serde_json::to_string_pretty(&resp_json).unwrap()
-    );
-
-    let result = resp_json["data"]["languages"]
-        .as_array()
-        .unwrap()
-        .into_iter()
-        .map(|item| {
-            (
-                item["name"].as_str().unwrap().to_string(),
-                item["language"].as_str().unwrap().to_string(),
-            )
-        })
-        .collect();
-    Ok(result)
-}
-
-async fn detect_language(
-    client: &reqwest::Client,
-    auth: &Auth,
-    text: &str,
-) -> reqwest::Result<String> {
-    const URL: &str = "https://translation.googleapis.com/language/translate/v2/detect";
-
-    let resp = client
-        .post(URL)
-        .header("Authorization", format!("Bearer {}", auth.token))
-        .header("x-goog-user-project", &auth.project)
-        .header("Content-Type", "application/json; charset=utf-8")
-        .body(format!(r#"{{"q": "{}"}}"#, text))
-        .send()
-        .await?;
-    let resp_json: serde_json::Value = resp.json().await?;
-    log::trace!(
-        "detect_language response: {}",
-        serde_json::to_string_pretty(&resp_json).unwrap()
-    );
-
-    let result = resp_json["data"]["detections"][0][0]["language"]
-        .as_str()
-        .unwrap()
-        .to_string();
-    Ok(result)
-}
-
-async fn translate(
-    client: &reqwest::Client,
-    auth: &Auth,
-    from: &str,
-    to: &str,
-    text: &str,
-) -> reqwest::Result<String> {
-    const URL: &str = "https://translation.googleapis.com/language/translate/v2";
-
-    let resp = client
-        .post(URL)
-        .header("Authorization", format!("Bearer {}", auth.token))
-        .header("x-goog-user-project", &auth.project)
-        .header("Content-Type", "application/json; charset=utf-8")
-        .body(
-            serde_json::json!({
-              "q": text,
-              "source": from,
-              "target": to,
-              "format": "text"
-            })
-            .to_string(),
-        )
-        .send()
-        .await?;
-    let resp_json: serde_json::Value = resp.json().await?;
-    log::trace!(
-        "translate response: {}",
-        serde_json::to_string_pretty(&resp_json).unwrap()
-    );
-
-    let result = resp_json["data"]["translations"][0]["translatedText"]
-        .as_str()
-        .unwrap_or_else(|| "<error>")
-        .to_string();
-    Ok(result)
-}
-
-fn load_auth_from_env() -> Auth {
-    Auth {
-        token: std::env::var("GCP_AUTH").unwrap(),
-        project: std::env::var("GCP_PROJECT").unwrap(),
-    }
-}
-
 #[derive(BotCommands, Clone)]
 #[command(
     rename_rule = "lowercase",
@@ -145,34 +45,38 @@ enum Command {
         to_language: String,
         text: String,
     },
+    #[command(description = "Pick languages from a keyboard, then send text to translate.")]
+    Translate,
 }
 
 async fn answer(bot: Bot, msg: Message, cmd: Command) -> ResponseResult<()> {
+    let translator = TRANSLATOR.get().unwrap().as_ref();
+    let locale = msg.from().and_then(|user| user.language_code.clone());
+
     match cmd {
-        Command::Help => {
-            bot.send_message(msg.chat.id, Command::descriptions().to_string())
-                .await?
-        }
+        Command::Help => bot.send_message(msg.chat.id, i18n::t(locale.as_deref(), "help")).await?,
         Command::Languages => {
+            let mut names: Vec<&String> = LANGUAGES.get().unwrap().keys().collect();
+            names.sort();
+            let names = names
+                .into_iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
             bot.send_message(
                 msg.chat.id,
-                format!(
-                    "{:?}",
-                    LANGUAGES
-                        .get()
-                        .unwrap()
-                        .keys()
-                        .into_iter()
-                        .collect::<Vec<_>>()
-                ),
+                format!("{} {}", i18n::t(locale.as_deref(), "languages_prefix"), names),
             )
             .await?
         }
         Command::DetectLanguage(text) => {
-            let result = detect_language(CLIENT.get().unwrap(), AUTH.get().unwrap(), &text).await;
+            let result = translator.detect(&text).await;
             match result {
                 Ok(lang) => bot.send_message(msg.chat.id, lang).await?,
-                Err(_) => bot.send_message(msg.chat.id, "Internal error").await?,
+                Err(err) => {
+                    bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                        .await?
+                }
             }
         }
         Command::TranslateTo {
@@ -185,23 +89,23 @@ async fn answer(bot: Bot, msg: Message, cmd: Command) -> ResponseResult<()> {
                 .get(&to_lang)
                 .cloned()
                 .unwrap_or(to_lang);
-            let result = detect_language(CLIENT.get().unwrap(), AUTH.get().unwrap(), &text).await;
-            let Ok(from_lang) = result else {
-                bot.send_message(msg.chat.id, "Internal error").await?;
-                return Ok(());
+            let result = translator.detect(&text).await;
+            let from_lang = match result {
+                Ok(from_lang) => from_lang,
+                Err(err) => {
+                    bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                        .await?;
+                    return Ok(());
+                }
             };
 
-            let result = translate(
-                CLIENT.get().unwrap(),
-                AUTH.get().unwrap(),
-                &from_lang,
-                &to_lang,
-                &text,
-            )
-            .await;
+            let result = translator.translate(&from_lang, &to_lang, &text).await;
             match result {
                 Ok(lang) => bot.send_message(msg.chat.id, lang).await?,
-                Err(_) => bot.send_message(msg.chat.id, "Internal error").await?,
+                Err(err) => {
+                    bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                        .await?
+                }
             }
         }
         Command::TranslateFromTo {
@@ -222,19 +126,17 @@ async fn answer(bot: Bot, msg: Message, cmd: Command) -> ResponseResult<()> {
                 .cloned()
                 .unwrap_or(to_lang);
 
-            let result = translate(
-                CLIENT.get().unwrap(),
-                AUTH.get().unwrap(),
-                &from_lang,
-                &to_lang,
-                &text,
-            )
-            .await;
+            let result = translator.translate(&from_lang, &to_lang, &text).await;
             match result {
                 Ok(lang) => bot.send_message(msg.chat.id, lang).await?,
-                Err(_) => bot.send_message(msg.chat.id, "Internal error").await?,
+                Err(err) => {
+                    bot.send_message(msg.chat.id, i18n::translate_error(locale.as_deref(), &err))
+                        .await?
+                }
             }
         }
+        // Intercepted by the dialogue branch in `main` before reaching this handler.
+        Command::Translate => unreachable!(),
     };
 
     Ok(())
@@ -245,18 +147,50 @@ async fn main() {
     pretty_env_logger::init();
     log::info!("Starting throw dice bot...");
 
-    let client = reqwest::Client::new();
-    CLIENT.set(client).unwrap();
+    i18n::load();
 
-    let auth = load_auth_from_env();
-    AUTH.set(auth).unwrap();
+    let client = reqwest::Client::new();
+    let translator = translator::load_from_env(client).await.unwrap();
 
-    let languages = get_languages(CLIENT.get().unwrap(), AUTH.get().unwrap())
-        .await
-        .unwrap();
+    let languages = translator.languages().await.unwrap();
     LANGUAGES.set(languages).unwrap();
 
+    TRANSLATOR.set(translator).unwrap();
+
     let bot = Bot::from_env();
 
-    Command::repl(bot, answer).await;
+    let message_handler = Update::filter_message()
+        .enter_dialogue::<Message, MyStorage, State>()
+        .branch(
+            dptree::entry()
+                .filter_command::<Command>()
+                .branch(dptree::case![Command::Translate].endpoint(dialogue::start))
+                .endpoint(answer),
+        )
+        .branch(
+            dptree::case![State::AwaitingText {
+                prompt_message_id,
+                from_lang,
+                to_lang
+            }]
+            .endpoint(dialogue::receive_text),
+        )
+        .branch(
+            dptree::filter(|msg: Message| document::is_translatable_document(&msg))
+                .endpoint(document::answer),
+        );
+
+    let callback_handler = Update::filter_callback_query()
+        .enter_dialogue::<CallbackQuery, MyStorage, State>()
+        .endpoint(dialogue::handle_callback);
+
+    let handler = dptree::entry()
+        .branch(message_handler)
+        .branch(callback_handler);
+
+    Dispatcher::builder(bot, handler)
+        .dependencies(dptree::deps![InMemStorage::<State>::new()])
+        .build()
+        .dispatch()
+        .await;
 }
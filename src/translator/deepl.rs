@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::auth::AuthError;
+
+use super::{TranslateError, Translator};
+
+#[derive(Debug, Deserialize)]
+struct DeepLLanguage {
+    language: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslation {
+    detected_source_language: String,
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslateResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLErrorResponse {
+    message: String,
+}
+
+async fn parse<T: for<'de> Deserialize<'de>>(resp: reqwest::Response) -> Result<T, TranslateError> {
+    let status = resp.status();
+    let body = resp.text().await?;
+    if !status.is_success() {
+        return Err(match status.as_u16() {
+            403 => TranslateError::AuthenticationFailed,
+            456 => TranslateError::QuotaExceeded,
+            _ => TranslateError::Provider {
+                status: status.to_string(),
+                message: serde_json::from_str::<DeepLErrorResponse>(&body)
+                    .map(|e| e.message)
+                    .unwrap_or(body),
+            },
+        });
+    }
+    serde_json::from_str(&body).map_err(|e| TranslateError::Provider {
+        status: status.to_string(),
+        message: e.to_string(),
+    })
+}
+
+/// DeepL's free/pro REST API, selected via `TRANSLATOR_PROVIDER=deepl`.
+pub struct DeepLTranslator {
+    client: reqwest::Client,
+    api_key: String,
+    base_url: String,
+}
+
+impl DeepLTranslator {
+    pub fn load_from_env(client: reqwest::Client) -> Result<Self, TranslateError> {
+        let api_key = std::env::var("DEEPL_AUTH_KEY")
+            .map_err(|_| AuthError::MissingEnvVar("DEEPL_AUTH_KEY"))?;
+        // Free-tier keys are routed through api-free.deepl.com; pro keys use api.deepl.com.
+        let base_url = if api_key.ends_with(":fx") {
+            "https://api-free.deepl.com".to_string()
+        } else {
+            "https://api.deepl.com".to_string()
+        };
+        Ok(Self {
+            client,
+            api_key,
+            base_url,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for DeepLTranslator {
+    async fn languages(&self) -> Result<HashMap<String, String>, TranslateError> {
+        let resp = self
+            .client
+            .get(format!("{}/v2/languages", self.base_url))
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .query(&[("type", "target")])
+            .send()
+            .await?;
+        let languages: Vec<DeepLLanguage> = parse(resp).await?;
+        Ok(languages
+            .into_iter()
+            .map(|lang| (lang.name, lang.language))
+            .collect())
+    }
+
+    async fn detect(&self, text: &str) -> Result<String, TranslateError> {
+        // DeepL has no standalone detect endpoint; a translate call with no
+        // `source_lang` returns the detected source language alongside the
+        // (unused, here) translation.
+        let resp = self
+            .client
+            .post(format!("{}/v2/translate", self.base_url))
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .form(&[("text", text), ("target_lang", "EN")])
+            .send()
+            .await?;
+        let resp: DeepLTranslateResponse = parse(resp).await?;
+        let translation = resp
+            .translations
+            .first()
+            .ok_or_else(|| TranslateError::Provider {
+                status: "OK".to_string(),
+                message: "no translations returned".to_string(),
+            })?;
+        Ok(translation.detected_source_language.clone())
+    }
+
+    async fn translate(&self, from: &str, to: &str, text: &str) -> Result<String, TranslateError> {
+        let resp = self
+            .client
+            .post(format!("{}/v2/translate", self.base_url))
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .form(&[("text", text), ("source_lang", from), ("target_lang", to)])
+            .send()
+            .await?;
+        let resp: DeepLTranslateResponse = parse(resp).await?;
+        let translation = resp
+            .translations
+            .first()
+            .ok_or_else(|| TranslateError::Provider {
+                status: "OK".to_string(),
+                message: "no translations returned".to_string(),
+            })?;
+        Ok(translation.text.clone())
+    }
+}
@@ -0,0 +1,18 @@
+/// Failure surfaced by a [`super::Translator`] backend. Kept small and
+/// user-facing so `answer`/`document::answer` can show something more useful
+/// than a generic error message.
+#[derive(Debug, thiserror::Error)]
+pub enum TranslateError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("{0}")]
+    Auth(#[from] crate::auth::AuthError),
+    #[error("authentication failed")]
+    AuthenticationFailed,
+    #[error("quota exceeded")]
+    QuotaExceeded,
+    #[error("unsupported language: {0}")]
+    UnsupportedLanguage(String),
+    #[error("provider error ({status}): {message}")]
+    Provider { status: String, message: String },
+}
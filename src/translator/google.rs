@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::auth::Auth;
+
+use super::{TranslateError, Translator};
+
+#[derive(Debug, Deserialize)]
+struct Language {
+    language: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguagesData {
+    languages: Vec<Language>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LanguagesResponse {
+    data: LanguagesData,
+}
+
+#[derive(Debug, Deserialize)]
+struct Detection {
+    language: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DetectData {
+    detections: Vec<Vec<Detection>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DetectResponse {
+    data: DetectData,
+}
+
+#[derive(Debug, Deserialize)]
+struct Translation {
+    #[serde(rename = "translatedText")]
+    translated_text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslateData {
+    translations: Vec<Translation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslateResponse {
+    data: TranslateData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    message: String,
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+fn error_from_envelope(status_code: reqwest::StatusCode, body: &str) -> TranslateError {
+    match serde_json::from_str::<ErrorEnvelope>(body) {
+        Ok(envelope) => match envelope.error.status.as_str() {
+            "UNAUTHENTICATED" | "PERMISSION_DENIED" => TranslateError::AuthenticationFailed,
+            "RESOURCE_EXHAUSTED" => TranslateError::QuotaExceeded,
+            "INVALID_ARGUMENT" if envelope.error.message.to_lowercase().contains("language") => {
+                TranslateError::UnsupportedLanguage(envelope.error.message)
+            }
+            _ => TranslateError::Provider {
+                status: envelope.error.status,
+                message: envelope.error.message,
+            },
+        },
+        Err(_) => TranslateError::Provider {
+            status: status_code.to_string(),
+            message: body.to_string(),
+        },
+    }
+}
+
+async fn parse<T: for<'de> Deserialize<'de>>(resp: reqwest::Response) -> Result<T, TranslateError> {
+    let status = resp.status();
+    let body = resp.text().await?;
+    if !status.is_success() {
+        return Err(error_from_envelope(status, &body));
+    }
+    serde_json::from_str(&body).map_err(|e| TranslateError::Provider {
+        status: status.to_string(),
+        message: e.to_string(),
+    })
+}
+
+/// Google Cloud Translation v2 REST API.
+pub struct GoogleTranslator {
+    client: reqwest::Client,
+    auth: Auth,
+}
+
+impl GoogleTranslator {
+    pub async fn load_from_env(client: reqwest::Client) -> Result<Self, TranslateError> {
+        let auth = Auth::load_from_env(&client).await?;
+        Ok(Self { client, auth })
+    }
+}
+
+#[async_trait::async_trait]
+impl Translator for GoogleTranslator {
+    async fn languages(&self) -> Result<HashMap<String, String>, TranslateError> {
+        const URL: &str = "https://translation.googleapis.com/language/translate/v2/languages";
+
+        let token = self.auth.token(&self.client).await?;
+        let resp = self
+            .client
+            .post(URL)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("x-goog-user-project", &self.auth.project)
+            .header("Content-Type", "application/json; charset=utf-8")
+            .body(r#"{"target": "en"}"#)
+            .send()
+            .await?;
+        let resp: LanguagesResponse = parse(resp).await?;
+
+        Ok(resp
+            .data
+            .languages
+            .into_iter()
+            .map(|lang| (lang.name, lang.language))
+            .collect())
+    }
+
+    async fn detect(&self, text: &str) -> Result<String, TranslateError> {
+        const URL: &str = "https://translation.googleapis.com/language/translate/v2/detect";
+
+        let token = self.auth.token(&self.client).await?;
+        let resp = self
+            .client
+            .post(URL)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("x-goog-user-project", &self.auth.project)
+            .header("Content-Type", "application/json; charset=utf-8")
+            .body(serde_json::json!({ "q": text }).to_string())
+            .send()
+            .await?;
+        let resp: DetectResponse = parse(resp).await?;
+
+        let detection = resp
+            .data
+            .detections
+            .into_iter()
+            .next()
+            .and_then(|detections| detections.into_iter().next())
+            .ok_or_else(|| TranslateError::Provider {
+                status: "OK".to_string(),
+                message: "no detections returned".to_string(),
+            })?;
+        Ok(detection.language)
+    }
+
+    async fn translate(&self, from: &str, to: &str, text: &str) -> Result<String, TranslateError> {
+        let translations = self.translate_batch(from, to, &[text.to_string()]).await?;
+        translations
+            .into_iter()
+            .next()
+            .ok_or_else(|| TranslateError::Provider {
+                status: "OK".to_string(),
+                message: "no translations returned".to_string(),
+            })
+    }
+
+    async fn translate_batch(
+        &self,
+        from: &str,
+        to: &str,
+        texts: &[String],
+    ) -> Result<Vec<String>, TranslateError> {
+        const URL: &str = "https://translation.googleapis.com/language/translate/v2";
+
+        let token = self.auth.token(&self.client).await?;
+        let resp = self
+            .client
+            .post(URL)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("x-goog-user-project", &self.auth.project)
+            .header("Content-Type", "application/json; charset=utf-8")
+            .body(
+                serde_json::json!({
+                  "q": texts,
+                  "source": from,
+                  "target": to,
+                  "format": "text"
+                })
+                .to_string(),
+            )
+            .send()
+            .await?;
+        let resp: TranslateResponse = parse(resp).await?;
+
+        Ok(resp
+            .data
+            .translations
+            .into_iter()
+            .map(|translation| translation.translated_text)
+            .collect())
+    }
+}
@@ -0,0 +1,71 @@
+mod deepl;
+mod error;
+mod google;
+
+use std::collections::HashMap;
+
+pub use deepl::DeepLTranslator;
+pub use error::TranslateError;
+pub use google::GoogleTranslator;
+
+/// A translation backend. Each implementation is responsible for building
+/// its own provider-native request body and parsing its own response shape
+/// rather than forcing a common wire format across providers.
+#[async_trait::async_trait]
+pub trait Translator {
+    /// Supported languages, keyed by display name with the language code as
+    /// the value (matching the shape the bot caches on startup).
+    async fn languages(&self) -> Result<HashMap<String, String>, TranslateError>;
+
+    /// Detects the language of `text`, returning a provider-native language
+    /// code.
+    async fn detect(&self, text: &str) -> Result<String, TranslateError>;
+
+    /// Translates `text` from `from` into `to`, both provider-native
+    /// language codes.
+    async fn translate(&self, from: &str, to: &str, text: &str) -> Result<String, TranslateError>;
+
+    /// Translates a batch of independent segments in as few round-trips as
+    /// the backend allows, preserving input order. The default falls back to
+    /// one request per segment; backends that accept an array of inputs per
+    /// request (e.g. Google's `q` list) should override this.
+    async fn translate_batch(
+        &self,
+        from: &str,
+        to: &str,
+        texts: &[String],
+    ) -> Result<Vec<String>, TranslateError> {
+        let mut result = Vec::with_capacity(texts.len());
+        for text in texts {
+            result.push(self.translate(from, to, text).await?);
+        }
+        Ok(result)
+    }
+}
+
+/// Which backend is active, selected via `TRANSLATOR_PROVIDER`.
+pub enum Provider {
+    Google,
+    DeepL,
+}
+
+impl Provider {
+    pub fn load_from_env() -> Self {
+        match std::env::var("TRANSLATOR_PROVIDER") {
+            Ok(provider) if provider.eq_ignore_ascii_case("deepl") => Provider::DeepL,
+            _ => Provider::Google,
+        }
+    }
+}
+
+/// Builds the configured backend. Google is used unless `TRANSLATOR_PROVIDER`
+/// is `deepl`; it reads `GCP_AUTH` as the path to a service-account JSON key
+/// (not a bearer token) and `GCP_PROJECT` as the project.
+pub async fn load_from_env(
+    client: reqwest::Client,
+) -> Result<Box<dyn Translator + Send + Sync>, TranslateError> {
+    match Provider::load_from_env() {
+        Provider::Google => Ok(Box::new(GoogleTranslator::load_from_env(client).await?)),
+        Provider::DeepL => Ok(Box::new(DeepLTranslator::load_from_env(client)?)),
+    }
+}